@@ -0,0 +1,177 @@
+//! Parses `.mtl` format which stores material data
+
+use std::io::BufRead;
+use std::collections::HashMap;
+use std::simd::f32x4;
+use error::ObjResult;
+use raw::lexer::lex;
+
+/// Parses a string into number.
+macro_rules! n {
+    ($input:expr) => ( try!($input.parse()) )
+}
+
+/// Parses &[&str] into &[f32].
+macro_rules! f {
+    ($args:expr) => (
+        &{
+            let mut ret = Vec::new();
+            for &arg in $args.iter() {
+                ret.push(try!(arg.parse::<f32>()))
+            }
+            ret
+        }[..]
+    )
+}
+
+/// Parses a wavefront `.mtl` format.
+pub fn parse_mtl<T: BufRead>(input: T) -> ObjResult<RawMtl> {
+    let mut materials = HashMap::new();
+    let mut current: Option<String> = None;
+
+    macro_rules! current {
+        () => (
+            match current {
+                Some(ref name) => materials.get_mut(name).unwrap(),
+                None => error!(WrongTypeOfArguments, "Expected 'newmtl' before any other statement")
+            }
+        )
+    }
+
+    try!(lex(input, |stmt, args| {
+        match stmt {
+            "newmtl" => match args {
+                [name] => {
+                    materials.insert(name.to_string(), Material::new());
+                    current = Some(name.to_string());
+                }
+                _ => error!(WrongNumberOfArguments, "Expected only 1 argument")
+            },
+
+            // Color and reflectivity
+            "Ka" => current!().ambient  = Some(try!(color(args))),
+            "Kd" => current!().diffuse  = Some(try!(color(args))),
+            "Ks" => current!().specular = Some(try!(color(args))),
+            "Ke" => current!().emissive = Some(try!(color(args))),
+
+            // Other numeric attributes
+            "Ns" => current!().shininess = Some(match f!(args) {
+                [ns] => ns,
+                _ => error!(WrongNumberOfArguments, "Expected only 1 argument")
+            }),
+            "d" => current!().dissolve = Some(match f!(args) {
+                [d] => d,
+                _ => error!(WrongNumberOfArguments, "Expected only 1 argument")
+            }),
+            "Tr" => current!().dissolve = Some(match f!(args) {
+                [tr] => 1.0 - tr,
+                _ => error!(WrongNumberOfArguments, "Expected only 1 argument")
+            }),
+            "Ni" => current!().optical_density = Some(match f!(args) {
+                [ni] => ni,
+                _ => error!(WrongNumberOfArguments, "Expected only 1 argument")
+            }),
+            "illum" => current!().illumination_model = Some(match args {
+                [illum] => n!(illum),
+                _ => error!(WrongNumberOfArguments, "Expected only 1 argument")
+            }),
+
+            // Texture maps
+            "map_Ka" => current!().ambient_map = Some(args.connect(" ")),
+            "map_Kd" => current!().diffuse_map = Some(args.connect(" ")),
+            "map_Ks" => current!().specular_map = Some(args.connect(" ")),
+            "map_Ns" => current!().shininess_map = Some(args.connect(" ")),
+            "map_d" => current!().dissolve_map = Some(args.connect(" ")),
+            "map_Bump" | "bump" => current!().bump_map = Some(args.connect(" ")),
+            "disp" => current!().displacement_map = Some(args.connect(" ")),
+            "decal" => current!().decal_map = Some(args.connect(" ")),
+
+            // Unexpected statement
+            _ => error!(UnexpectedStatement, "Received unknown statement")
+        }
+
+        Ok(())
+    }));
+
+    Ok(RawMtl { materials: materials })
+}
+
+/// Parses a `[r, g, b]` or `[s]` triple into a RGB color.
+fn color(args: &[&str]) -> ObjResult<f32x4> {
+    match f!(args) {
+        [r, g, b] => Ok(f32x4(r, g, b, 0.0)),
+        [s] => Ok(f32x4(s, s, s, 0.0)),
+        _ => error!(WrongNumberOfArguments, "Expected 1 or 3 arguments")
+    }
+}
+
+
+/// Low-level Rust binding for `.mtl` format.
+pub struct RawMtl {
+    /// Materials which are defined by `newmtl`, keyed by name.
+    pub materials: HashMap<String, Material>
+}
+
+/// A single material defined between a `newmtl` statement and the next.
+pub struct Material {
+    /// Ambient color (`Ka`).
+    pub ambient: Option<f32x4>,
+    /// Diffuse color (`Kd`).
+    pub diffuse: Option<f32x4>,
+    /// Specular color (`Ks`).
+    pub specular: Option<f32x4>,
+    /// Emissive color (`Ke`).
+    pub emissive: Option<f32x4>,
+
+    /// Specular exponent (`Ns`).
+    pub shininess: Option<f32>,
+    /// Dissolve, i.e. opacity (`d`, or `1 - Tr`).
+    pub dissolve: Option<f32>,
+    /// Optical density, i.e. index of refraction (`Ni`).
+    pub optical_density: Option<f32>,
+    /// Illumination model (`illum`).
+    pub illumination_model: Option<usize>,
+
+    /// Ambient texture map (`map_Ka`).
+    pub ambient_map: Option<String>,
+    /// Diffuse texture map (`map_Kd`).
+    pub diffuse_map: Option<String>,
+    /// Specular texture map (`map_Ks`).
+    pub specular_map: Option<String>,
+    /// Specular exponent texture map (`map_Ns`).
+    pub shininess_map: Option<String>,
+    /// Dissolve texture map (`map_d`).
+    pub dissolve_map: Option<String>,
+    /// Bump texture map (`map_Bump`/`bump`).
+    pub bump_map: Option<String>,
+    /// Displacement texture map (`disp`).
+    pub displacement_map: Option<String>,
+    /// Decal texture map (`decal`).
+    pub decal_map: Option<String>
+}
+
+impl Material {
+    /// Constructs a new, empty `Material`.
+    fn new() -> Self {
+        Material {
+            ambient: None,
+            diffuse: None,
+            specular: None,
+            emissive: None,
+
+            shininess: None,
+            dissolve: None,
+            optical_density: None,
+            illumination_model: None,
+
+            ambient_map: None,
+            diffuse_map: None,
+            specular_map: None,
+            shininess_map: None,
+            dissolve_map: None,
+            bump_map: None,
+            displacement_map: None,
+            decal_map: None
+        }
+    }
+}
@@ -29,6 +29,20 @@ macro_rules! s {
     ($param:ident) => ( &$param.split('/').collect::<Vec<&str>>()[..] )
 }
 
+/// Parses a (possibly negative, relative) index into an absolute 0-based index, bounds-checked
+/// against `$count`, the number of elements parsed so far.
+macro_rules! idx {
+    ($input:expr, $count:expr) => {{
+        let i: isize = n!($input);
+        let count = $count as isize;
+        let resolved = if i < 0 { count + i } else { i - 1 };
+        if resolved < 0 || resolved >= count {
+            error!(IndexOutOfRange, "Expected valid vertex index")
+        }
+        resolved as usize
+    }}
+}
+
 /// Parses a wavefront `.obj` format.
 pub fn parse_obj<T: BufRead>(input: T) -> ObjResult<RawObj> {
     let mut name = None;
@@ -39,10 +53,56 @@ pub fn parse_obj<T: BufRead>(input: T) -> ObjResult<RawObj> {
     let mut normals = Vec::new();
     let mut param_vertices = Vec::new();
 
-    let points = Vec::new();
-    let lines = Vec::new();
+    let mut points = Vec::new();
+    let mut lines = Vec::new();
     let mut polygons = Vec::new();
 
+    // `cstype`/`deg`/`bmat`/`step` group attributes, in effect for the next `curv`/`curv2`/`surf`
+    let mut cs_rational = false;
+    let mut cs_geometry: Option<BasisMatrix> = None;
+    let mut cs_degree: (usize, usize) = (0, 0);
+    let mut cs_step: (f32, f32) = (1.0, 1.0);
+    let mut cs_bmat_u: Option<Vec<f32>> = None;
+    let mut cs_bmat_v: Option<Vec<f32>> = None;
+
+    let mut curves: Vec<Curve> = Vec::new();
+    let mut curves2d: Vec<Curve2D> = Vec::new();
+    let mut surfaces: Vec<Surface> = Vec::new();
+
+    /// The free-form element currently open between its statement and the closing `end`.
+    enum ActiveFreeForm { Curve(usize), Curve2D(usize), Surface(usize) }
+    let mut active: Option<ActiveFreeForm> = None;
+
+    /// Snapshots the `cstype`/`deg`/`bmat`/`step` group attributes for a new curve/surface.
+    macro_rules! free_form {
+        () => {{
+            let geometry = match cs_geometry {
+                Some(geometry) => geometry,
+                None => error!(WrongTypeOfArguments, "Expected 'cstype' before 'curv', 'curv2' or 'surf'")
+            };
+            FreeForm {
+                rational: cs_rational,
+                geometry: geometry,
+                degree: cs_degree,
+                step: cs_step,
+                basis_matrix_u: cs_bmat_u.clone(),
+                basis_matrix_v: cs_bmat_v.clone()
+            }
+        }}
+    }
+
+    /// Returns a mutable reference to the body of the currently active free-form element.
+    macro_rules! active_body {
+        () => (
+            match active {
+                Some(ActiveFreeForm::Curve(i))   => &mut curves[i].body,
+                Some(ActiveFreeForm::Curve2D(i)) => &mut curves2d[i].body,
+                Some(ActiveFreeForm::Surface(i)) => &mut surfaces[i].body,
+                None => error!(WrongTypeOfArguments, "Expected 'curv', 'curv2' or 'surf' before this statement")
+            }
+        )
+    }
+
     let counter = Counter::new(&points, &lines, &polygons);
     let mut group_builder       = counter.hash_map("default".to_string());
     let mut mesh_builder        = counter.hash_map(String::new());
@@ -76,33 +136,82 @@ pub fn parse_obj<T: BufRead>(input: T) -> ObjResult<RawObj> {
 
             // Free-form curve / surface attributes
             "cstype" => {
-                let _rational: bool;
+                let rational: bool;
                 let geometry = match args {
-                    ["rat", ty] => { _rational = true; ty }
-                    [ty] => { _rational = false; ty }
+                    ["rat", ty] => { rational = true; ty }
+                    [ty] => { rational = false; ty }
                     _ => error!(WrongTypeOfArguments, "Expected 'rat xxx' or 'xxx' format")
                 };
 
-                match geometry {
-                    "bmatrix" => unimplemented!(),
-                    "bezier" => unimplemented!(),
-                    "bspline" => unimplemented!(),
-                    "cardinal" => unimplemented!(),
-                    "taylor" => unimplemented!(),
+                cs_geometry = Some(match geometry {
+                    "bmatrix" => BasisMatrix::Bmatrix,
+                    "bezier" => BasisMatrix::Bezier,
+                    "bspline" => BasisMatrix::Bspline,
+                    "cardinal" => BasisMatrix::Cardinal,
+                    "taylor" => BasisMatrix::Taylor,
                     _ => error!(WrongTypeOfArguments, "Expected one of 'bmatrix', 'bezier', 'bspline', 'cardinal' and 'taylor'")
+                });
+                cs_rational = rational;
+            }
+            "deg" => cs_degree = match f!(args) {
+                [deg_u, deg_v]  => (deg_u as usize, deg_v as usize),
+                [deg_u] => (deg_u as usize, 0),
+                _ => error!(WrongNumberOfArguments, "Expected 1 or 2 arguments")
+            },
+            "bmat" => {
+                if args.len() < 2 { error!(WrongNumberOfArguments, "Expected a direction and at least 1 matrix value") }
+                let values = f!(&args[1..]).to_vec();
+                match args[0] {
+                    "u" => cs_bmat_u = Some(values),
+                    "v" => cs_bmat_v = Some(values),
+                    _ => error!(WrongTypeOfArguments, "Expected 'u' or 'v' as the first argument")
                 }
             }
-            "deg" => match f!(args) {
-                [_deg_u, _deg_v]  => unimplemented!(),
-                [_deg_u] => unimplemented!(),
+            "step" => cs_step = match f!(args) {
+                [step_u, step_v] => (step_u, step_v),
+                [step_u] => (step_u, step_u),
                 _ => error!(WrongNumberOfArguments, "Expected 1 or 2 arguments")
             },
-            "bmat" => unimplemented!(),
-            "step" => unimplemented!(),
 
             // Elements
-            "p" => unimplemented!(),
-            "l" => unimplemented!(),
+            "p" => {
+                if args.len() < 1 { error!(WrongNumberOfArguments, "Expected at least 1 argument") }
+
+                for &arg in args.iter() {
+                    points.push(idx!(arg, positions.len()));
+                }
+            }
+            "l" => {
+                if args.len() < 2 { error!(WrongNumberOfArguments, "Expected at least 2 arguments") }
+
+                let mut args = args.iter();
+                let first = args.next().unwrap();
+
+                macro_rules! m {
+                    { $($pat:pat => $name:ident[$exp:expr]),* } => (
+                        match s!(first) {
+                            $($pat => {
+                                let mut vertices = vec![ $exp ];
+                                for param in args {
+                                    match s!(param) {
+                                        $pat => vertices.push($exp),
+                                        _ => error!(WrongTypeOfArguments, "Expected every vertex to share the same '#' or '#/#' format")
+                                    }
+                                }
+                                for pair in vertices.windows(2) {
+                                    lines.push(Line::$name([pair[0], pair[1]]));
+                                }
+                            },)*
+                            _ => error!(WrongTypeOfArguments, "Expected '#' or '#/#' format")
+                        }
+                    )
+                }
+
+                m! {
+                    [p]    => P[idx!(p, positions.len())],
+                    [p, t] => PT[(idx!(p, positions.len()), idx!(t, tex_coords.len()))]
+                };
+            }
             "f" => {
                 if args.len() < 3 { error!(WrongNumberOfArguments, "Expected at least 3 arguments") }
 
@@ -128,23 +237,131 @@ pub fn parse_obj<T: BufRead>(input: T) -> ObjResult<RawObj> {
                 }
 
                 polygons.push(m! {
-                    [p]        => P[n!(p) - 1],
-                    [p, t]     => PT[(n!(p) - 1, n!(t) - 1)],
-                    [p, "", u] => PN[(n!(p) - 1, n!(u) - 1)],
-                    [p, t, u]  => PTN[(n!(p) - 1, n!(t) - 1, n!(u) - 1)]
+                    [p]        => P[idx!(p, positions.len())],
+                    [p, t]     => PT[(idx!(p, positions.len()), idx!(t, tex_coords.len()))],
+                    [p, "", u] => PN[(idx!(p, positions.len()), idx!(u, normals.len()))],
+                    [p, t, u]  => PTN[(idx!(p, positions.len()), idx!(t, tex_coords.len()), idx!(u, normals.len()))]
+                });
+            }
+            "curv" => {
+                if args.len() < 4 { error!(WrongNumberOfArguments, "Expected a parameter range and at least 2 control points") }
+
+                let range = (n!(args[0]), n!(args[1]));
+                let control_points = {
+                    let mut control_points = Vec::new();
+                    for &arg in args[2..].iter() {
+                        control_points.push(idx!(arg, positions.len()));
+                    }
+                    control_points
+                };
+
+                active = Some(ActiveFreeForm::Curve(curves.len()));
+                curves.push(Curve {
+                    group: free_form!(),
+                    range: range,
+                    control_points: control_points,
+                    body: FreeFormBody::default()
+                });
+            }
+            "curv2" => {
+                if args.len() < 2 { error!(WrongNumberOfArguments, "Expected at least 2 control points") }
+
+                let control_points = {
+                    let mut control_points = Vec::new();
+                    for &arg in args.iter() {
+                        control_points.push(idx!(arg, param_vertices.len()));
+                    }
+                    control_points
+                };
+
+                active = Some(ActiveFreeForm::Curve2D(curves2d.len()));
+                curves2d.push(Curve2D {
+                    group: free_form!(),
+                    control_points: control_points,
+                    body: FreeFormBody::default()
+                });
+            }
+            "surf" => {
+                if args.len() < 5 { error!(WrongNumberOfArguments, "Expected two parameter ranges and at least 1 control point") }
+
+                let range_u = (n!(args[0]), n!(args[1]));
+                let range_v = (n!(args[2]), n!(args[3]));
+
+                let mut args = args[4..].iter();
+                let first = args.next().unwrap();
+
+                macro_rules! m {
+                    { $($pat:pat => $name:ident[$exp:expr]),* } => (
+                        match s!(first) {
+                            $($pat => Polygon::$name({
+                                let mut polygon = vec![ $exp ];
+                                for param in args {
+                                    match s!(param) {
+                                        $pat => polygon.push($exp),
+                                        _ => error!(WrongTypeOfArguments, "Expected every control point to share the same '#', '#/#', '#//#' or '#/#/#' format")
+                                    }
+                                }
+                                polygon
+                            }),)*
+                            _ => error!(WrongTypeOfArguments, "Expected '#', '#/#', '#//#' or '#/#/#' format")
+                        }
+                    )
+                }
+
+                let control_points = m! {
+                    [p]        => P[idx!(p, positions.len())],
+                    [p, t]     => PT[(idx!(p, positions.len()), idx!(t, tex_coords.len()))],
+                    [p, "", u] => PN[(idx!(p, positions.len()), idx!(u, normals.len()))],
+                    [p, t, u]  => PTN[(idx!(p, positions.len()), idx!(t, tex_coords.len()), idx!(u, normals.len()))]
+                };
+
+                active = Some(ActiveFreeForm::Surface(surfaces.len()));
+                surfaces.push(Surface {
+                    group: free_form!(),
+                    range_u: range_u,
+                    range_v: range_v,
+                    control_points: control_points,
+                    body: FreeFormBody::default()
                 });
             }
-            "curv" => unimplemented!(),
-            "curv2" => unimplemented!(),
-            "surf" => unimplemented!(),
 
             // Free-form curve / surface body statements
-            "parm" => unimplemented!(),
-            "trim" => unimplemented!(),
-            "hole" => unimplemented!(),
-            "scrv" => unimplemented!(),
-            "sp" => unimplemented!(),
-            "end" => unimplemented!(),
+            "parm" => {
+                if args.len() < 2 { error!(WrongNumberOfArguments, "Expected a direction and at least 1 value") }
+
+                let values = f!(&args[1..]).to_vec();
+                match args[0] {
+                    "u" => active_body!().parameters_u.extend(values),
+                    "v" => active_body!().parameters_v.extend(values),
+                    _ => error!(WrongTypeOfArguments, "Expected 'u' or 'v' as the first argument")
+                }
+            }
+            "trim" => {
+                let curve2d_count = curves2d.len();
+                active_body!().trim.push(try!(curve_segments(args, curve2d_count)));
+            }
+            "hole" => {
+                let curve2d_count = curves2d.len();
+                active_body!().hole.push(try!(curve_segments(args, curve2d_count)));
+            }
+            "scrv" => {
+                if args.is_empty() { error!(WrongNumberOfArguments, "Expected at least 1 argument") }
+
+                let mut refs = Vec::new();
+                for &arg in args.iter() {
+                    refs.push(idx!(arg, curves2d.len()));
+                }
+                active_body!().special_curves.push(refs);
+            }
+            "sp" => {
+                if args.is_empty() { error!(WrongNumberOfArguments, "Expected at least 1 argument") }
+
+                for &arg in args.iter() {
+                    let sp = idx!(arg, param_vertices.len());
+                    active_body!().special_points.push(sp);
+                }
+            }
+            "end" => active = None,
 
             // Connectivity between free-form surfaces
             "con" => unimplemented!(),
@@ -212,6 +429,10 @@ pub fn parse_obj<T: BufRead>(input: T) -> ObjResult<RawObj> {
         lines: lines,
         polygons: polygons,
 
+        curves: curves,
+        curves2d: curves2d,
+        surfaces: surfaces,
+
         groups: group_builder.result,
         meshes: mesh_builder.result,
         smoothing_groups: smoothing_builder.result,
@@ -220,6 +441,23 @@ pub fn parse_obj<T: BufRead>(input: T) -> ObjResult<RawObj> {
 }
 
 
+/// Parses a `trim`/`hole` body statement into `(u0, u1, curve2d)` segments.
+fn curve_segments(args: &[&str], curve2d_count: usize) -> ObjResult<Vec<CurveSegment>> {
+    if args.is_empty() || args.len() % 3 != 0 {
+        error!(WrongNumberOfArguments, "Expected triples of 'u0 u1 curve2d'")
+    }
+
+    let mut segments = Vec::new();
+    for chunk in args.chunks(3) {
+        segments.push(CurveSegment {
+            range: (n!(chunk[0]), n!(chunk[1])),
+            curve2d: idx!(chunk[2], curve2d_count)
+        });
+    }
+    Ok(segments)
+}
+
+
 /// Counts current total count of parsed `points`, `lines` and `polygons`.
 struct Counter {
     points:     *const Vec<Point>,
@@ -402,6 +640,13 @@ pub struct RawObj {
     /// Polygons which store the index data of vectors.
     pub polygons: Vec<Polygon>,
 
+    /// Free-form curves, constructed by `curv` statements.
+    pub curves: Vec<Curve>,
+    /// Free-form 2D curves in parameter space, constructed by `curv2` statements.
+    pub curves2d: Vec<Curve2D>,
+    /// Free-form surfaces, constructed by `surf` statements.
+    pub surfaces: Vec<Surface>,
+
     /// Groups of multiple geometries.
     pub groups: HashMap<String, Group>,
     /// Geometries which consist in a same material.
@@ -412,6 +657,109 @@ pub struct RawObj {
     pub merging_groups: VecMap<Group>
 }
 
+impl RawObj {
+    /// Converts every polygon into a fan of triangles, preserving each vertex's original data
+    /// shape (`P`/`PT`/`PN`/`PTN`).
+    pub fn triangulated_polygons(&self) -> Vec<Polygon> {
+        self.polygons.iter().flat_map(|polygon| polygon.triangulate()).collect()
+    }
+
+    /// Computes per-vertex normals for polygons which don't already carry one (`P`/`PT`), and
+    /// rewrites them into `PN`/`PTN` pointing at the new entries appended to `self.normals`.
+    ///
+    /// Each normal is the normalized sum of the normalized face normals of the triangles sharing
+    /// that vertex. Vertices in different smoothing groups never share an accumulated normal;
+    /// when smoothing is off (no `s` group, or `s 0`/`s off`), every face gets its own normal
+    /// instead of sharing by position at all. This produces faceted shading across a smoothing
+    /// group boundary (or between any two faces when smoothing is off) and smooth shading within
+    /// a single smoothing group.
+    pub fn compute_normals(&mut self) {
+        /// Identifies which normals may be shared: faces in the same smoothing group share by
+        /// position, faces with smoothing off never share (each gets its own key).
+        #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+        enum Smoothing { Group(usize), Off(usize) }
+
+        fn smoothing_of(groups: &VecMap<Group>, polygon_index: usize) -> Smoothing {
+            for (group, ranges) in groups.iter() {
+                if ranges.polygons.iter().any(|range| range.start <= polygon_index && polygon_index < range.end) {
+                    return Smoothing::Group(group)
+                }
+            }
+            Smoothing::Off(polygon_index)
+        }
+
+        fn positions_of(polygon: &Polygon) -> Vec<usize> {
+            match *polygon {
+                Polygon::P(ref verts) => verts.clone(),
+                Polygon::PT(ref verts) => verts.iter().map(|&(p, _)| p).collect(),
+                Polygon::PN(ref verts) => verts.iter().map(|&(p, _)| p).collect(),
+                Polygon::PTN(ref verts) => verts.iter().map(|&(p, _, _)| p).collect()
+            }
+        }
+
+        fn cross(a: (f32, f32, f32), b: (f32, f32, f32)) -> (f32, f32, f32) {
+            (a.1 * b.2 - a.2 * b.1, a.2 * b.0 - a.0 * b.2, a.0 * b.1 - a.1 * b.0)
+        }
+
+        fn normalize(v: (f32, f32, f32)) -> (f32, f32, f32) {
+            let len = (v.0 * v.0 + v.1 * v.1 + v.2 * v.2).sqrt();
+            if len == 0.0 { v } else { (v.0 / len, v.1 / len, v.2 / len) }
+        }
+
+        // (smoothing key, position index) -> index into `sums`
+        let mut indices: HashMap<(Smoothing, usize), usize> = HashMap::new();
+        let mut sums: Vec<(f32, f32, f32)> = Vec::new();
+
+        for (polygon_index, polygon) in self.polygons.iter().enumerate() {
+            match *polygon {
+                Polygon::P(_) | Polygon::PT(_) => {}
+                _ => continue
+            }
+
+            let smoothing = smoothing_of(&self.smoothing_groups, polygon_index);
+
+            for triangle in polygon.triangulate() {
+                let verts = positions_of(&triangle);
+                let v0 = self.positions[verts[0]];
+                let v1 = self.positions[verts[1]];
+                let v2 = self.positions[verts[2]];
+                let edge1 = (v1.0 - v0.0, v1.1 - v0.1, v1.2 - v0.2);
+                let edge2 = (v2.0 - v0.0, v2.1 - v0.1, v2.2 - v0.2);
+                let normal = normalize(cross(edge1, edge2));
+
+                for &p in verts.iter() {
+                    let i = *indices.entry((smoothing, p)).or_insert_with(|| {
+                        sums.push((0.0, 0.0, 0.0));
+                        sums.len() - 1
+                    });
+                    let sum = sums[i];
+                    sums[i] = (sum.0 + normal.0, sum.1 + normal.1, sum.2 + normal.2);
+                }
+            }
+        }
+
+        let base = self.normals.len();
+        self.normals.extend(sums.iter().map(|&v| {
+            let n = normalize(v);
+            f32x4(n.0, n.1, n.2, 0.0)
+        }));
+
+        for (polygon_index, polygon) in self.polygons.iter_mut().enumerate() {
+            let smoothing = smoothing_of(&self.smoothing_groups, polygon_index);
+
+            *polygon = match *polygon {
+                Polygon::P(ref verts) => Polygon::PN(verts.iter().map(|&p| {
+                    (p, base + indices[&(smoothing, p)])
+                }).collect()),
+                Polygon::PT(ref verts) => Polygon::PTN(verts.iter().map(|&(p, t)| {
+                    (p, t, base + indices[&(smoothing, p)])
+                }).collect()),
+                _ => continue
+            };
+        }
+    }
+}
+
 /// The `Point` type which stores the index of the position vector.
 pub type Point = usize;
 
@@ -437,6 +785,30 @@ pub enum Polygon {
     PTN(Vec<(usize, usize, usize)>)
 }
 
+impl Polygon {
+    /// Splits `self` into a triangle fan `[(v0, v1, v2), (v0, v2, v3), ...]`, preserving the
+    /// variant and per-vertex data of the original polygon. Polygons which are already triangles
+    /// pass through unchanged.
+    fn triangulate(&self) -> Vec<Polygon> {
+        macro_rules! fan {
+            ($name:ident, $verts:expr) => {{
+                let verts = $verts;
+                assert!(verts.len() >= 3, "Expected at least 3 vertices");
+                (1..verts.len() - 1).map(|i| {
+                    Polygon::$name(vec![verts[0], verts[i], verts[i + 1]])
+                }).collect()
+            }}
+        }
+
+        match *self {
+            Polygon::P(ref verts) => fan!(P, verts),
+            Polygon::PT(ref verts) => fan!(PT, verts),
+            Polygon::PN(ref verts) => fan!(PN, verts),
+            Polygon::PTN(ref verts) => fan!(PTN, verts)
+        }
+    }
+}
+
 /// A group which contains ranges of points, lines and polygons
 #[derive(Clone, Debug)]
 pub struct Group {
@@ -457,3 +829,106 @@ pub struct Range {
     /// The upper bound of the range (exclusive).
     pub end: usize
 }
+
+
+/// The basis matrix type of a free-form curve or surface, as declared by `cstype`.
+#[derive(Copy, PartialEq, Eq, Clone, Debug)]
+pub enum BasisMatrix {
+    /// `bmatrix`: an explicit, custom basis matrix supplied by `bmat`.
+    Bmatrix,
+    /// `bezier`: a Bezier curve/surface.
+    Bezier,
+    /// `bspline`: a B-spline curve/surface.
+    Bspline,
+    /// `cardinal`: a Cardinal (Catmull-Rom) curve/surface.
+    Cardinal,
+    /// `taylor`: a Taylor curve/surface.
+    Taylor
+}
+
+/// The `cstype`/`deg`/`bmat`/`step` group attributes in effect when a `curv`/`curv2`/`surf`
+/// statement was parsed.
+#[derive(Clone, Debug)]
+pub struct FreeForm {
+    /// Whether the curve/surface is rational (`cstype rat ...`).
+    pub rational: bool,
+    /// The basis matrix type, from `cstype`.
+    pub geometry: BasisMatrix,
+    /// Degree in the `u` and (for surfaces) `v` direction, from `deg`.
+    pub degree: (usize, usize),
+    /// Step size in the `u` and (for surfaces) `v` direction, from `step`.
+    pub step: (f32, f32),
+    /// Explicit basis matrix in the `u` direction, from `bmat u ...`.
+    pub basis_matrix_u: Option<Vec<f32>>,
+    /// Explicit basis matrix in the `v` direction, from `bmat v ...`.
+    pub basis_matrix_v: Option<Vec<f32>>
+}
+
+/// A free-form curve, constructed by a `curv` statement.
+#[derive(Clone, Debug)]
+pub struct Curve {
+    /// The `cstype`/`deg`/`bmat`/`step` attributes in effect when this curve was parsed.
+    pub group: FreeForm,
+    /// Parameter range `(start, end)` of the curve.
+    pub range: (f32, f32),
+    /// Control points, indexing into `RawObj::positions`.
+    pub control_points: Vec<usize>,
+    /// Body statements (`parm`/`trim`/`hole`/`scrv`/`sp`) up to the closing `end`.
+    pub body: FreeFormBody
+}
+
+/// A free-form 2D curve in parameter space, constructed by a `curv2` statement. Typically used
+/// to trim or cut a hole into a `Surface`.
+#[derive(Clone, Debug)]
+pub struct Curve2D {
+    /// The `cstype`/`deg`/`bmat`/`step` attributes in effect when this curve was parsed.
+    pub group: FreeForm,
+    /// Control points, indexing into `RawObj::param_vertices`.
+    pub control_points: Vec<usize>,
+    /// Body statements (`parm`/`trim`/`hole`/`scrv`/`sp`) up to the closing `end`.
+    pub body: FreeFormBody
+}
+
+/// A free-form surface, constructed by a `surf` statement.
+#[derive(Clone, Debug)]
+pub struct Surface {
+    /// The `cstype`/`deg`/`bmat`/`step` attributes in effect when this surface was parsed.
+    pub group: FreeForm,
+    /// Parameter range `(start, end)` in the `u` direction.
+    pub range_u: (f32, f32),
+    /// Parameter range `(start, end)` in the `v` direction.
+    pub range_v: (f32, f32),
+    /// Control points, in the same `p`, `p/t`, `p//n` or `p/t/n` shape as a face's `Polygon`.
+    pub control_points: Polygon,
+    /// Body statements (`parm`/`trim`/`hole`/`scrv`/`sp`) up to the closing `end`.
+    pub body: FreeFormBody
+}
+
+/// The `parm`/`trim`/`hole`/`scrv`/`sp` body statements of a free-form curve or surface, up to
+/// the closing `end`.
+#[derive(Clone, Debug, Default)]
+pub struct FreeFormBody {
+    /// Parameter (knot) values in the `u` direction, from `parm u ...`.
+    pub parameters_u: Vec<f32>,
+    /// Parameter (knot) values in the `v` direction, from `parm v ...`.
+    pub parameters_v: Vec<f32>,
+    /// Outer trimming loops, each a sequence of segments along a `Curve2D`.
+    pub trim: Vec<Vec<CurveSegment>>,
+    /// Inner trimming loops (holes), same shape as `trim`.
+    pub hole: Vec<Vec<CurveSegment>>,
+    /// Special curves connecting this element to a `Curve2D`, one `Vec` of references per `scrv`
+    /// statement.
+    pub special_curves: Vec<Vec<usize>>,
+    /// Special points, indexing into `RawObj::param_vertices`.
+    pub special_points: Vec<usize>
+}
+
+/// A `(start, end)` parameter sub-range of a `Curve2D`, referenced by index into
+/// `RawObj::curves2d`.
+#[derive(Copy, Clone, Debug)]
+pub struct CurveSegment {
+    /// Parameter range `(start, end)` along the referenced curve.
+    pub range: (f32, f32),
+    /// Index into `RawObj::curves2d`.
+    pub curve2d: usize
+}